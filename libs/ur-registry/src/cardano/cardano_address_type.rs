@@ -0,0 +1,23 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CardanoAddressType {
+    #[default]
+    Base,
+    Byron,
+}
+
+impl CardanoAddressType {
+    pub fn from_u32(v: u32) -> Option<Self> {
+        match v {
+            0 => Some(CardanoAddressType::Base),
+            1 => Some(CardanoAddressType::Byron),
+            _ => None,
+        }
+    }
+
+    pub fn to_u32(self) -> u32 {
+        match self {
+            CardanoAddressType::Base => 0,
+            CardanoAddressType::Byron => 1,
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::cbor::cbor_map;
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::error::{URError, URResult};
+use crate::registry_types::{RegistryType, CARDANO_SIGN_DATA_REQUEST, CRYPTO_KEYPATH, UUID};
+use crate::traits::{From as FromCbor, RegistryItem, To};
+use crate::types::Bytes;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag};
+use minicbor::encode::{Error, Write};
+use minicbor::{Decoder, Encoder};
+
+const REQUEST_ID: u8 = 1;
+const SIGN_DATA: u8 = 2;
+const DERIVATION_PATH: u8 = 3;
+const ORIGIN: u8 = 4;
+const NETWORK_ID: u8 = 5;
+const PROTOCOL_MAGIC: u8 = 6;
+const HASH_PAYLOAD: u8 = 7;
+
+#[derive(Debug, Clone, Default)]
+pub struct CardanoSignDataRequest {
+    request_id: Option<Bytes>,
+    sign_data: Bytes,
+    derivation_path: CryptoKeyPath,
+    origin: Option<String>,
+    network_id: Option<u8>,
+    protocol_magic: Option<u32>,
+    hash_payload: bool,
+}
+
+impl CardanoSignDataRequest {
+    pub fn default() -> Self {
+        Default::default()
+    }
+    pub fn set_request_id(&mut self, id: Option<Bytes>) {
+        self.request_id = id;
+    }
+    pub fn set_sign_data(&mut self, data: Bytes) {
+        self.sign_data = data
+    }
+    pub fn set_derivation_path(&mut self, derivation_path: CryptoKeyPath) {
+        self.derivation_path = derivation_path;
+    }
+    pub fn set_origin(&mut self, origin: Option<String>) {
+        self.origin = origin;
+    }
+    pub fn set_network_id(&mut self, network_id: Option<u8>) {
+        self.network_id = network_id;
+    }
+    pub fn set_protocol_magic(&mut self, protocol_magic: Option<u32>) {
+        self.protocol_magic = protocol_magic;
+    }
+    pub fn set_hash_payload(&mut self, hash_payload: bool) {
+        self.hash_payload = hash_payload;
+    }
+    pub fn get_request_id(&self) -> Option<Bytes> {
+        self.request_id.clone()
+    }
+    pub fn get_sign_data(&self) -> Bytes {
+        self.sign_data.clone()
+    }
+    pub fn get_derivation_path(&self) -> CryptoKeyPath {
+        self.derivation_path.clone()
+    }
+    pub fn get_origin(&self) -> Option<String> {
+        self.origin.clone()
+    }
+    pub fn get_network_id(&self) -> Option<u8> {
+        self.network_id
+    }
+    pub fn get_protocol_magic(&self) -> Option<u32> {
+        self.protocol_magic
+    }
+    pub fn get_hash_payload(&self) -> bool {
+        self.hash_payload
+    }
+
+    pub fn new(
+        request_id: Option<Bytes>,
+        sign_data: Bytes,
+        derivation_path: CryptoKeyPath,
+        origin: Option<String>,
+        network_id: Option<u8>,
+        protocol_magic: Option<u32>,
+        hash_payload: bool,
+    ) -> Self {
+        Self {
+            request_id,
+            sign_data,
+            derivation_path,
+            origin,
+            network_id,
+            protocol_magic,
+            hash_payload,
+        }
+    }
+
+    fn get_map_size(&self) -> u64 {
+        let mut size = 2;
+        if let Some(_) = self.request_id {
+            size = size + 1;
+        }
+        if let Some(_) = self.origin {
+            size = size + 1;
+        }
+        if let Some(_) = self.network_id {
+            size = size + 1;
+        }
+        if let Some(_) = self.protocol_magic {
+            size = size + 1;
+        }
+        if self.hash_payload {
+            size = size + 1;
+        }
+        size
+    }
+}
+
+impl RegistryItem for CardanoSignDataRequest {
+    fn get_registry_type() -> RegistryType<'static> {
+        CARDANO_SIGN_DATA_REQUEST
+    }
+}
+
+impl<C> minicbor::Encode<C> for CardanoSignDataRequest {
+    fn encode<W: Write>(&self, e: &mut Encoder<W>, _ctx: &mut C) -> Result<(), Error<W::Error>> {
+        e.map(self.get_map_size())?;
+
+        if let Some(request_id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(request_id)?;
+        }
+
+        e.int(Int::from(SIGN_DATA))?.bytes(&self.sign_data)?;
+
+        e.int(Int::from(DERIVATION_PATH))?
+            .tag(Tag::Unassigned(CRYPTO_KEYPATH.get_tag()))?;
+        self.derivation_path.encode(e, _ctx)?;
+
+        if let Some(origin) = &self.origin {
+            e.int(Int::from(ORIGIN))?.str(origin)?;
+        }
+
+        if let Some(network_id) = &self.network_id {
+            e.int(Int::from(NETWORK_ID))?.u8(*network_id)?;
+        }
+
+        if let Some(protocol_magic) = &self.protocol_magic {
+            e.int(Int::from(PROTOCOL_MAGIC))?.u32(*protocol_magic)?;
+        }
+
+        if self.hash_payload {
+            e.int(Int::from(HASH_PAYLOAD))?.bool(self.hash_payload)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'b, C> minicbor::Decode<'b, C> for CardanoSignDataRequest {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut cardano_sign_data_request = CardanoSignDataRequest::default();
+        cbor_map(d, &mut cardano_sign_data_request, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    d.tag()?;
+                    obj.set_request_id(Some(d.bytes()?.to_vec()));
+                }
+                SIGN_DATA => {
+                    obj.set_sign_data(d.bytes()?.to_vec());
+                }
+                DERIVATION_PATH => {
+                    d.tag()?;
+                    obj.set_derivation_path(CryptoKeyPath::decode(d, _ctx)?);
+                }
+                ORIGIN => obj.set_origin(Some(d.str()?.to_string())),
+                NETWORK_ID => {
+                    obj.set_network_id(Some(d.u8()?));
+                }
+                PROTOCOL_MAGIC => {
+                    obj.set_protocol_magic(Some(d.u32()?));
+                }
+                HASH_PAYLOAD => {
+                    obj.set_hash_payload(d.bool()?);
+                }
+                _ => {}
+            }
+            Ok(())
+        })?;
+        Ok(cardano_sign_data_request)
+    }
+}
+
+impl To for CardanoSignDataRequest {
+    fn to_bytes(&self) -> URResult<Vec<u8>> {
+        minicbor::to_vec(self.clone()).map_err(|e| URError::CborDecodeError(e.to_string()))
+    }
+}
+
+impl FromCbor<CardanoSignDataRequest> for CardanoSignDataRequest {
+    fn from_cbor(bytes: Vec<u8>) -> URResult<CardanoSignDataRequest> {
+        minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_key_path::PathComponent;
+    use alloc::vec;
+
+    fn signing_path() -> CryptoKeyPath {
+        CryptoKeyPath::new(
+            vec![
+                PathComponent::new(Some(1852), true).unwrap(),
+                PathComponent::new(Some(1815), true).unwrap(),
+                PathComponent::new(Some(0), true).unwrap(),
+                PathComponent::new(Some(0), false).unwrap(),
+                PathComponent::new(Some(0), false).unwrap(),
+            ],
+            Some([0x73, 0xc5, 0xda, 0x0a]),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_construct_minimal() {
+        let cardano_sign_data_request = CardanoSignDataRequest::new(
+            None,
+            hex::decode("48656c6c6f").unwrap(),
+            signing_path(),
+            None,
+            None,
+            None,
+            false,
+        );
+
+        assert_eq!(
+            "a2024548656c6c6f03d90130a2018a19073cf5190717f500f500f400f4021a73c5da0a",
+            hex::encode(cardano_sign_data_request.to_bytes().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_construct() {
+        let request_id = Some(
+            [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            ]
+            .to_vec(),
+        );
+
+        let cardano_sign_data_request = CardanoSignDataRequest::new(
+            request_id,
+            hex::decode("48656c6c6f").unwrap(),
+            signing_path(),
+            Some("cardano-wallet".to_string()),
+            Some(1),
+            Some(764824073),
+            true,
+        );
+
+        assert_eq!(
+            "a701d825500102030405060708090a0b0c0d0e0f10024548656c6c6f03d90130a2018a19073cf5190717f500f500f400f4021a73c5da0a046e63617264616e6f2d77616c6c65740501061a2d964a0907f5",
+            hex::encode(cardano_sign_data_request.to_bytes().unwrap())
+        );
+    }
+}
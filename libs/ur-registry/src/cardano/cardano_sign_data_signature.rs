@@ -0,0 +1,146 @@
+use crate::cbor::cbor_map;
+use crate::error::{URError, URResult};
+use crate::impl_template_struct;
+use crate::registry_types::UUID;
+use crate::traits::{From as FromCbor, MapSize, To};
+use crate::types::Bytes;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag};
+use minicbor::encode::{Error, Write};
+use minicbor::{Decoder, Encoder};
+
+const REQUEST_ID: u8 = 1;
+const SIGNATURE: u8 = 2;
+const PUBLIC_KEY: u8 = 3;
+
+impl_template_struct!(CardanoSignDataSignature {
+    request_id: Option<Bytes>,
+    signature: Bytes,
+    public_key: Option<Bytes>
+});
+
+impl MapSize for CardanoSignDataSignature {
+    fn map_size(&self) -> u64 {
+        let mut size = 1;
+        if self.request_id.is_some() {
+            size = size + 1;
+        }
+        if self.public_key.is_some() {
+            size = size + 1;
+        }
+        size
+    }
+}
+
+impl<C> minicbor::Encode<C> for CardanoSignDataSignature {
+    fn encode<W: Write>(&self, e: &mut Encoder<W>, _ctx: &mut C) -> Result<(), Error<W::Error>> {
+        e.map(self.map_size())?;
+
+        if let Some(id) = &self.request_id {
+            e.int(Int::from(REQUEST_ID))?
+                .tag(Tag::Unassigned(UUID.get_tag()))?
+                .bytes(id)?;
+        }
+
+        e.int(Int::from(SIGNATURE))?
+            .bytes(self.get_signature().as_ref())?;
+
+        if let Some(public_key) = &self.public_key {
+            e.int(Int::from(PUBLIC_KEY))?.bytes(public_key)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'b, C> minicbor::Decode<'b, C> for CardanoSignDataSignature {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut cardano_sign_data_signature = CardanoSignDataSignature::default();
+        cbor_map(d, &mut cardano_sign_data_signature, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                REQUEST_ID => {
+                    d.tag()?;
+                    obj.set_request_id(Some(d.bytes()?.to_vec()));
+                }
+                SIGNATURE => {
+                    obj.set_signature(d.bytes()?.to_vec());
+                }
+                PUBLIC_KEY => {
+                    obj.set_public_key(Some(d.bytes()?.to_vec()));
+                }
+                _ => {}
+            }
+            Ok(())
+        })?;
+        Ok(cardano_sign_data_signature)
+    }
+}
+
+impl To for CardanoSignDataSignature {
+    fn to_bytes(&self) -> URResult<Vec<u8>> {
+        minicbor::to_vec(self.clone()).map_err(|e| URError::CborDecodeError(e.to_string()))
+    }
+}
+
+impl FromCbor<CardanoSignDataSignature> for CardanoSignDataSignature {
+    fn from_cbor(bytes: Vec<u8>) -> URResult<CardanoSignDataSignature> {
+        minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_construct_minimal() {
+        let mut cardano_sign_data_signature = CardanoSignDataSignature::default();
+        cardano_sign_data_signature.set_signature(hex::decode("deadbeef").unwrap());
+
+        assert_eq!(
+            "a10244deadbeef",
+            hex::encode(cardano_sign_data_signature.to_bytes().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_construct() {
+        let mut cardano_sign_data_signature = CardanoSignDataSignature::default();
+        cardano_sign_data_signature.set_request_id(Some(
+            [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            ]
+            .to_vec(),
+        ));
+        cardano_sign_data_signature.set_signature(hex::decode("deadbeef").unwrap());
+
+        assert_eq!(
+            "a201d825500102030405060708090a0b0c0d0e0f100244deadbeef",
+            hex::encode(cardano_sign_data_signature.to_bytes().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_construct_with_public_key() {
+        let mut cardano_sign_data_signature = CardanoSignDataSignature::default();
+        cardano_sign_data_signature.set_request_id(Some(
+            [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            ]
+            .to_vec(),
+        ));
+        cardano_sign_data_signature.set_signature(hex::decode("deadbeef").unwrap());
+        cardano_sign_data_signature.set_public_key(Some(
+            hex::decode("0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20")
+                .unwrap(),
+        ));
+
+        assert_eq!(
+            "a301d825500102030405060708090a0b0c0d0e0f100244deadbeef0358200102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+            hex::encode(cardano_sign_data_signature.to_bytes().unwrap())
+        );
+    }
+}
@@ -12,10 +12,12 @@ use minicbor::{Decoder, Encoder};
 
 const REQUEST_ID: u8 = 1;
 const WITNESS_SET: u8 = 2;
+const PUBLIC_KEY: u8 = 3;
 
 impl_template_struct!(CardanoSignature {
     request_id: Option<Bytes>,
-    witness_set: Bytes
+    witness_set: Bytes,
+    public_key: Option<Bytes>
 });
 
 impl MapSize for CardanoSignature {
@@ -24,6 +26,9 @@ impl MapSize for CardanoSignature {
         if self.request_id.is_some() {
             size = size + 1;
         }
+        if self.public_key.is_some() {
+            size = size + 1;
+        }
         size
     }
 }
@@ -41,6 +46,10 @@ impl<C> minicbor::Encode<C> for CardanoSignature {
         e.int(Int::from(WITNESS_SET))?
             .bytes(self.get_witness_set().as_ref())?;
 
+        if let Some(public_key) = &self.public_key {
+            e.int(Int::from(PUBLIC_KEY))?.bytes(public_key)?;
+        }
+
         Ok(())
     }
 }
@@ -59,6 +68,9 @@ impl<'b, C> minicbor::Decode<'b, C> for CardanoSignature {
                 WITNESS_SET => {
                     obj.set_witness_set(d.bytes()?.to_vec());
                 }
+                PUBLIC_KEY => {
+                    obj.set_public_key(Some(d.bytes()?.to_vec()));
+                }
                 _ => {}
             }
             Ok(())
@@ -78,3 +90,43 @@ impl FromCbor<CardanoSignature> for CardanoSignature {
         minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_id() -> Bytes {
+        [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+        ]
+        .to_vec()
+    }
+
+    #[test]
+    fn test_construct_without_public_key() {
+        let mut cardano_signature = CardanoSignature::default();
+        cardano_signature.set_request_id(Some(request_id()));
+        cardano_signature.set_witness_set(hex::decode("cafebabe").unwrap());
+
+        assert_eq!(
+            "a201d825500102030405060708090a0b0c0d0e0f100244cafebabe",
+            hex::encode(cardano_signature.to_bytes().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_construct_with_public_key() {
+        let mut cardano_signature = CardanoSignature::default();
+        cardano_signature.set_request_id(Some(request_id()));
+        cardano_signature.set_witness_set(hex::decode("cafebabe").unwrap());
+        cardano_signature.set_public_key(Some(
+            hex::decode("0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20")
+                .unwrap(),
+        ));
+
+        assert_eq!(
+            "a301d825500102030405060708090a0b0c0d0e0f100244cafebabe0358200102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+            hex::encode(cardano_signature.to_bytes().unwrap())
+        );
+    }
+}
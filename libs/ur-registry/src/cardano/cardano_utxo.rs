@@ -0,0 +1,294 @@
+use crate::cardano::cardano_address_type::CardanoAddressType;
+use crate::cbor::cbor_map;
+use crate::crypto_key_path::CryptoKeyPath;
+use crate::error::{URError, URResult};
+use crate::registry_types::{RegistryType, CARDANO_UTXO, CRYPTO_KEYPATH};
+use crate::traits::{From as FromCbor, RegistryItem, To};
+use crate::types::Bytes;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use minicbor::data::{Int, Tag};
+use minicbor::encode::{Error, Write};
+use minicbor::{Decoder, Encoder};
+
+const TRANSACTION_HASH: u8 = 1;
+const INDEX: u8 = 2;
+const AMOUNT: u8 = 3;
+const PATH: u8 = 4;
+const ADDRESS: u8 = 5;
+const ADDRESS_TYPE: u8 = 6;
+const BYRON_ATTRIBUTES: u8 = 7;
+
+#[derive(Debug, Clone, Default)]
+pub struct CardanoUTXO {
+    transaction_hash: Bytes,
+    index: u32,
+    amount: u64,
+    path: CryptoKeyPath,
+    address: String,
+    address_type: Option<CardanoAddressType>,
+    byron_attributes: Option<Bytes>,
+}
+
+impl CardanoUTXO {
+    pub fn set_transaction_hash(&mut self, transaction_hash: Bytes) {
+        self.transaction_hash = transaction_hash;
+    }
+    pub fn set_index(&mut self, index: u32) {
+        self.index = index;
+    }
+    pub fn set_amount(&mut self, amount: u64) {
+        self.amount = amount;
+    }
+    pub fn set_path(&mut self, path: CryptoKeyPath) {
+        self.path = path;
+    }
+    pub fn set_address(&mut self, address: String) {
+        self.address = address;
+    }
+    fn set_address_type(&mut self, address_type: Option<CardanoAddressType>) {
+        self.address_type = address_type;
+    }
+    fn set_byron_attributes(&mut self, byron_attributes: Option<Bytes>) {
+        self.byron_attributes = byron_attributes;
+    }
+    pub fn set_byron(&mut self, byron_attributes: Bytes) {
+        self.address_type = Some(CardanoAddressType::Byron);
+        self.byron_attributes = Some(byron_attributes);
+    }
+    pub fn clear_byron(&mut self) {
+        self.address_type = None;
+        self.byron_attributes = None;
+    }
+    pub fn get_transaction_hash(&self) -> Bytes {
+        self.transaction_hash.clone()
+    }
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+    pub fn get_amount(&self) -> u64 {
+        self.amount
+    }
+    pub fn get_path(&self) -> CryptoKeyPath {
+        self.path.clone()
+    }
+    pub fn get_address(&self) -> String {
+        self.address.clone()
+    }
+    pub fn get_address_type(&self) -> Option<CardanoAddressType> {
+        self.address_type
+    }
+    pub fn get_byron_attributes(&self) -> Option<Bytes> {
+        self.byron_attributes.clone()
+    }
+    pub fn is_byron_address(&self) -> bool {
+        matches!(self.address_type, Some(CardanoAddressType::Byron))
+    }
+
+    pub fn new(
+        transaction_hash: Bytes,
+        index: u32,
+        amount: u64,
+        path: CryptoKeyPath,
+        address: String,
+    ) -> Self {
+        Self {
+            transaction_hash,
+            index,
+            amount,
+            path,
+            address,
+            address_type: None,
+            byron_attributes: None,
+        }
+    }
+
+    pub fn new_byron(
+        transaction_hash: Bytes,
+        index: u32,
+        amount: u64,
+        path: CryptoKeyPath,
+        address: String,
+        byron_attributes: Bytes,
+    ) -> Self {
+        Self {
+            transaction_hash,
+            index,
+            amount,
+            path,
+            address,
+            address_type: Some(CardanoAddressType::Byron),
+            byron_attributes: Some(byron_attributes),
+        }
+    }
+
+    fn get_map_size(&self) -> u64 {
+        let mut size = 5;
+        if let Some(_) = self.address_type {
+            size = size + 1;
+        }
+        if let Some(_) = self.byron_attributes {
+            size = size + 1;
+        }
+        size
+    }
+}
+
+impl RegistryItem for CardanoUTXO {
+    fn get_registry_type() -> RegistryType<'static> {
+        CARDANO_UTXO
+    }
+}
+
+impl<C> minicbor::Encode<C> for CardanoUTXO {
+    fn encode<W: Write>(&self, e: &mut Encoder<W>, _ctx: &mut C) -> Result<(), Error<W::Error>> {
+        e.map(self.get_map_size())?;
+
+        e.int(Int::from(TRANSACTION_HASH))?
+            .bytes(&self.transaction_hash)?;
+
+        e.int(Int::from(INDEX))?.u32(self.index)?;
+
+        e.int(Int::from(AMOUNT))?.u64(self.amount)?;
+
+        e.int(Int::from(PATH))?
+            .tag(Tag::Unassigned(CRYPTO_KEYPATH.get_tag()))?;
+        self.path.encode(e, _ctx)?;
+
+        e.int(Int::from(ADDRESS))?.str(&self.address)?;
+
+        if let Some(address_type) = &self.address_type {
+            e.int(Int::from(ADDRESS_TYPE))?
+                .u32(address_type.to_u32())?;
+        }
+
+        if let Some(byron_attributes) = &self.byron_attributes {
+            e.int(Int::from(BYRON_ATTRIBUTES))?.bytes(byron_attributes)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'b, C> minicbor::Decode<'b, C> for CardanoUTXO {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let mut cardano_utxo = CardanoUTXO::default();
+        cbor_map(d, &mut cardano_utxo, |key, obj, d| {
+            let key =
+                u8::try_from(key).map_err(|e| minicbor::decode::Error::message(e.to_string()))?;
+            match key {
+                TRANSACTION_HASH => {
+                    obj.set_transaction_hash(d.bytes()?.to_vec());
+                }
+                INDEX => {
+                    obj.set_index(d.u32()?);
+                }
+                AMOUNT => {
+                    obj.set_amount(d.u64()?);
+                }
+                PATH => {
+                    d.tag()?;
+                    obj.set_path(CryptoKeyPath::decode(d, _ctx)?);
+                }
+                ADDRESS => {
+                    obj.set_address(d.str()?.to_string());
+                }
+                ADDRESS_TYPE => {
+                    let address_type = CardanoAddressType::from_u32(d.u32()?).ok_or_else(|| {
+                        minicbor::decode::Error::message("invalid cardano address type")
+                    })?;
+                    obj.set_address_type(Some(address_type));
+                }
+                BYRON_ATTRIBUTES => {
+                    obj.set_byron_attributes(Some(d.bytes()?.to_vec()));
+                }
+                _ => {}
+            }
+            Ok(())
+        })?;
+        match (&cardano_utxo.address_type, &cardano_utxo.byron_attributes) {
+            (Some(CardanoAddressType::Byron), None) => {
+                return Err(minicbor::decode::Error::message(
+                    "a Byron-address CardanoUTXO is missing byron_attributes",
+                ));
+            }
+            (None, Some(_)) | (Some(CardanoAddressType::Base), Some(_)) => {
+                return Err(minicbor::decode::Error::message(
+                    "byron_attributes is only valid for a Byron-address CardanoUTXO",
+                ));
+            }
+            _ => {}
+        }
+        Ok(cardano_utxo)
+    }
+}
+
+impl To for CardanoUTXO {
+    fn to_bytes(&self) -> URResult<Vec<u8>> {
+        minicbor::to_vec(self.clone()).map_err(|e| URError::CborDecodeError(e.to_string()))
+    }
+}
+
+impl FromCbor<CardanoUTXO> for CardanoUTXO {
+    fn from_cbor(bytes: Vec<u8>) -> URResult<CardanoUTXO> {
+        minicbor::decode(&bytes).map_err(|e| URError::CborDecodeError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_key_path::PathComponent;
+    use alloc::vec;
+
+    fn signing_path() -> CryptoKeyPath {
+        CryptoKeyPath::new(
+            vec![
+                PathComponent::new(Some(1852), true).unwrap(),
+                PathComponent::new(Some(1815), true).unwrap(),
+                PathComponent::new(Some(0), true).unwrap(),
+                PathComponent::new(Some(0), false).unwrap(),
+                PathComponent::new(Some(0), false).unwrap(),
+            ],
+            Some([0x73, 0xc5, 0xda, 0x0a]),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_construct_byron() {
+        let utxo = CardanoUTXO::new_byron(
+            hex::decode("aabbccdd").unwrap(),
+            0,
+            0,
+            signing_path(),
+            "addr1x".to_string(),
+            hex::decode("deadbeef").unwrap(),
+        );
+
+        assert_eq!(
+            "a70144aabbccdd0200030004d90130a2018a19073cf5190717f500f500f400f4021a73c5da0a056661646472317806010744deadbeef",
+            hex::encode(utxo.to_bytes().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown_address_type() {
+        let bytes = hex::decode(
+            "a60144aabbccdd0200030004d90130a2018a19073cf5190717f500f500f400f4021a73c5da0a0566616464723178061863",
+        )
+        .unwrap();
+
+        assert!(CardanoUTXO::from_cbor(bytes).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_byron_without_attributes() {
+        let bytes = hex::decode(
+            "a60144aabbccdd0200030004d90130a2018a19073cf5190717f500f500f400f4021a73c5da0a05666164647231780601",
+        )
+        .unwrap();
+
+        assert!(CardanoUTXO::from_cbor(bytes).is_err());
+    }
+}
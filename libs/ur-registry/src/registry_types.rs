@@ -0,0 +1,23 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegistryType<'a>(pub &'a str, pub u64);
+
+impl<'a> RegistryType<'a> {
+    pub fn get_type(&self) -> &'a str {
+        self.0
+    }
+    pub fn get_tag(&self) -> u64 {
+        self.1
+    }
+}
+
+pub const UUID: RegistryType = RegistryType("uuid", 37);
+pub const CRYPTO_KEYPATH: RegistryType = RegistryType("crypto-keypath", 304);
+
+pub const CARDANO_SIGN_REQUEST: RegistryType = RegistryType("cardano-sign-request", 2200);
+pub const CARDANO_UTXO: RegistryType = RegistryType("cardano-utxo", 2201);
+pub const CARDANO_SIGNATURE: RegistryType = RegistryType("cardano-signature", 2202);
+pub const CARDANO_CERT_KEY: RegistryType = RegistryType("cardano-cert-key", 2204);
+pub const CARDANO_SIGN_DATA_REQUEST: RegistryType =
+    RegistryType("cardano-sign-data-request", 2205);
+pub const CARDANO_SIGN_DATA_SIGNATURE: RegistryType =
+    RegistryType("cardano-sign-data-signature", 2206);